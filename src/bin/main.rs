@@ -22,7 +22,7 @@ pub fn get_linux_bno055_i2c_device() -> Result<LinuxI2CDevice, LinuxI2CError> {
 fn main() {
     match get_linux_bno055_i2c_device() {
         Ok(device) => {
-            let mut bno = BNO055::new(device).unwrap();
+            let mut bno = BNO055::new(I2cInterface::new(device)).unwrap();
             bno.set_mode(BNO055OperationMode::Ndof).unwrap();
             loop {
                 println!("{:?}", bno.get_quaternion().unwrap());
@@ -0,0 +1,317 @@
+//! Transport abstraction so [BNO055](../struct.BNO055.html) isn't tied to Linux i2cdev.
+
+use std::error::Error;
+use std::fmt;
+
+use i2cdev::core::I2CDevice;
+
+/// The register-level operations the BNO055 driver needs from its transport. One impl
+/// wraps an [I2CDevice](../../i2cdev/core/trait.I2CDevice.html) for Linux/Android, the
+/// other speaks the chip's UART register protocol over a [SerialPort](trait.SerialPort.html)
+/// for bare-metal/serial use.
+pub trait Bno055Interface {
+    type Error: Error;
+
+    fn read_register(&mut self, reg: u8) -> Result<u8, Self::Error>;
+    fn write_register(&mut self, reg: u8, value: u8) -> Result<(), Self::Error>;
+    fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error>;
+    fn write_registers(&mut self, reg: u8, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// [Bno055Interface](trait.Bno055Interface.html) backed by a Linux/Android
+/// [I2CDevice](../../i2cdev/core/trait.I2CDevice.html)
+pub struct I2cInterface<T: I2CDevice> {
+    pub i2cdev: T,
+}
+
+impl<T: I2CDevice> I2cInterface<T> {
+    pub fn new(i2cdev: T) -> Self {
+        I2cInterface { i2cdev: i2cdev }
+    }
+}
+
+impl<T: I2CDevice> Bno055Interface for I2cInterface<T> {
+    type Error = T::Error;
+
+    fn read_register(&mut self, reg: u8) -> Result<u8, Self::Error> {
+        self.i2cdev.smbus_read_byte_data(reg)
+    }
+
+    fn write_register(&mut self, reg: u8, value: u8) -> Result<(), Self::Error> {
+        self.i2cdev.smbus_write_byte_data(reg, value)
+    }
+
+    fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let data = self.i2cdev.smbus_read_i2c_block_data(reg, buf.len() as u8)?;
+        buf.copy_from_slice(&data);
+        Ok(())
+    }
+
+    fn write_registers(&mut self, reg: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.i2cdev.smbus_write_block_data(reg, data)
+    }
+}
+
+/// A byte-oriented serial port, implemented by the host's UART driver. Used by
+/// [UartInterface](struct.UartInterface.html) to speak the BNO055's UART register
+/// protocol (section 2, "UART interface" in the datasheet).
+pub trait SerialPort {
+    type Error: Error;
+
+    fn write_bytes(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+    fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Errors produced by [UartInterface](struct.UartInterface.html)
+#[derive(Debug)]
+pub enum UartInterfaceError<E> {
+    /// The underlying [SerialPort](trait.SerialPort.html) returned an error
+    Serial(E),
+    /// The device replied with a response header byte that isn't valid for the
+    /// transaction (0xBB or 0xEE for a read, 0xEE for a write)
+    UnexpectedHeader(u8),
+    /// A read reply's declared payload length didn't match the requested length
+    UnexpectedLength(u8),
+    /// The device reported a status code other than success, bus-overrun, or busy
+    Status(u8),
+    /// The transaction kept getting a bus-overrun/busy status past the retry limit
+    RetriesExhausted,
+}
+
+impl<E: Error> fmt::Display for UartInterfaceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UartInterfaceError::Serial(ref e) => write!(f, "serial port error: {}", e),
+            UartInterfaceError::UnexpectedHeader(b) => write!(f, "unexpected response header byte: {:#04x}", b),
+            UartInterfaceError::UnexpectedLength(len) => write!(f, "unexpected response payload length: {}", len),
+            UartInterfaceError::Status(code) => write!(f, "device reported status code: {:#04x}", code),
+            UartInterfaceError::RetriesExhausted => write!(f, "retries exhausted"),
+        }
+    }
+}
+
+impl<E: Error> Error for UartInterfaceError<E> {}
+
+const UART_START_BYTE: u8 = 0xAA;
+const UART_WRITE_RESPONSE: u8 = 0xEE;
+const UART_READ_RESPONSE: u8 = 0xBB;
+const UART_WRITE_SUCCESS: u8 = 0x01;
+const UART_STATUS_BUS_OVERRUN: u8 = 0x07;
+const UART_STATUS_BUSY: u8 = 0x05;
+
+/// [Bno055Interface](trait.Bno055Interface.html) backed by the BNO055's UART register
+/// protocol, for use on bare-metal MCUs or over a host serial port. Retries a
+/// transaction when the device reports bus-overrun (0x07) or busy (0x05).
+pub struct UartInterface<S: SerialPort> {
+    serial: S,
+    max_retries: u8,
+}
+
+impl<S: SerialPort> UartInterface<S> {
+    pub fn new(serial: S) -> Self {
+        UartInterface {
+            serial: serial,
+            max_retries: 3,
+        }
+    }
+
+    fn is_retryable(status: u8) -> bool {
+        status == UART_STATUS_BUS_OVERRUN || status == UART_STATUS_BUSY
+    }
+
+    fn do_write(&mut self, reg: u8, data: &[u8]) -> Result<(), UartInterfaceError<S::Error>> {
+        self.serial
+            .write_bytes(&[UART_START_BYTE, 0x00, reg, data.len() as u8])
+            .map_err(UartInterfaceError::Serial)?;
+        self.serial.write_bytes(data).map_err(
+            UartInterfaceError::Serial,
+        )?;
+
+        let mut header = [0u8; 2];
+        self.serial.read_bytes(&mut header).map_err(
+            UartInterfaceError::Serial,
+        )?;
+        if header[0] != UART_WRITE_RESPONSE {
+            return Err(UartInterfaceError::UnexpectedHeader(header[0]));
+        }
+        if header[1] != UART_WRITE_SUCCESS {
+            return Err(UartInterfaceError::Status(header[1]));
+        }
+        Ok(())
+    }
+
+    fn do_read(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), UartInterfaceError<S::Error>> {
+        self.serial
+            .write_bytes(&[UART_START_BYTE, 0x01, reg, buf.len() as u8])
+            .map_err(UartInterfaceError::Serial)?;
+
+        let mut header = [0u8; 2];
+        self.serial.read_bytes(&mut header).map_err(
+            UartInterfaceError::Serial,
+        )?;
+        if header[0] == UART_WRITE_RESPONSE {
+            return Err(UartInterfaceError::Status(header[1]));
+        }
+        if header[0] != UART_READ_RESPONSE {
+            return Err(UartInterfaceError::UnexpectedHeader(header[0]));
+        }
+        if header[1] as usize != buf.len() {
+            return Err(UartInterfaceError::UnexpectedLength(header[1]));
+        }
+
+        self.serial.read_bytes(buf).map_err(
+            UartInterfaceError::Serial,
+        )
+    }
+
+    fn with_retries<F>(&mut self, mut attempt: F) -> Result<(), UartInterfaceError<S::Error>>
+    where
+        F: FnMut(&mut Self) -> Result<(), UartInterfaceError<S::Error>>,
+    {
+        for _ in 0..=self.max_retries {
+            match attempt(self) {
+                Err(UartInterfaceError::Status(status)) if Self::is_retryable(status) => continue,
+                result => return result,
+            }
+        }
+        Err(UartInterfaceError::RetriesExhausted)
+    }
+}
+
+impl<S: SerialPort> Bno055Interface for UartInterface<S> {
+    type Error = UartInterfaceError<S::Error>;
+
+    fn read_register(&mut self, reg: u8) -> Result<u8, Self::Error> {
+        let mut buf = [0u8; 1];
+        self.read_registers(reg, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    fn write_register(&mut self, reg: u8, value: u8) -> Result<(), Self::Error> {
+        self.write_registers(reg, &[value])
+    }
+
+    fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+        self.with_retries(|this| this.do_read(reg, buf))
+    }
+
+    fn write_registers(&mut self, reg: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.with_retries(|this| this.do_write(reg, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl fmt::Display for MockError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "mock serial error")
+        }
+    }
+
+    impl Error for MockError {}
+
+    /// A [SerialPort](../trait.SerialPort.html) backed by canned reply bytes, for
+    /// exercising [UartInterface](../struct.UartInterface.html)'s framing without
+    /// real hardware.
+    struct MockSerial {
+        to_read: VecDeque<u8>,
+        written: Vec<u8>,
+    }
+
+    impl MockSerial {
+        fn new(to_read: &[u8]) -> Self {
+            MockSerial {
+                to_read: to_read.iter().cloned().collect(),
+                written: Vec::new(),
+            }
+        }
+    }
+
+    impl SerialPort for MockSerial {
+        type Error = MockError;
+
+        fn write_bytes(&mut self, data: &[u8]) -> Result<(), MockError> {
+            self.written.extend_from_slice(data);
+            Ok(())
+        }
+
+        fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), MockError> {
+            for b in buf.iter_mut() {
+                *b = self.to_read.pop_front().ok_or(MockError)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn read_register_parses_successful_response() {
+        let serial = MockSerial::new(&[UART_READ_RESPONSE, 0x01, 0x42]);
+        let mut iface = UartInterface::new(serial);
+        assert_eq!(iface.read_register(0x00).unwrap(), 0x42);
+        assert_eq!(iface.serial.written, vec![UART_START_BYTE, 0x01, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn write_register_parses_successful_response() {
+        let serial = MockSerial::new(&[UART_WRITE_RESPONSE, UART_WRITE_SUCCESS]);
+        let mut iface = UartInterface::new(serial);
+        iface.write_register(0x3F, 0x20).unwrap();
+        assert_eq!(
+            iface.serial.written,
+            vec![UART_START_BYTE, 0x00, 0x3F, 0x01, 0x20]
+        );
+    }
+
+    #[test]
+    fn read_register_rejects_unexpected_header() {
+        let serial = MockSerial::new(&[0x00, 0x01, 0x42]);
+        let mut iface = UartInterface::new(serial);
+        match iface.read_register(0x00) {
+            Err(UartInterfaceError::UnexpectedHeader(0x00)) => {}
+            other => panic!("expected UnexpectedHeader, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn read_register_rejects_unexpected_length() {
+        let serial = MockSerial::new(&[UART_READ_RESPONSE, 0x02, 0x42]);
+        let mut iface = UartInterface::new(serial);
+        match iface.read_register(0x00) {
+            Err(UartInterfaceError::UnexpectedLength(0x02)) => {}
+            other => panic!("expected UnexpectedLength, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn with_retries_retries_on_bus_overrun_then_succeeds() {
+        let serial = MockSerial::new(&[
+            UART_WRITE_RESPONSE,
+            UART_STATUS_BUS_OVERRUN,
+            UART_WRITE_RESPONSE,
+            UART_WRITE_SUCCESS,
+        ]);
+        let mut iface = UartInterface::new(serial);
+        iface.write_register(0x3F, 0x20).unwrap();
+    }
+
+    #[test]
+    fn with_retries_gives_up_after_max_retries() {
+        let mut bytes = Vec::new();
+        for _ in 0..10 {
+            bytes.push(UART_WRITE_RESPONSE);
+            bytes.push(UART_STATUS_BUS_OVERRUN);
+        }
+        let serial = MockSerial::new(&bytes);
+        let mut iface = UartInterface::new(serial);
+        match iface.write_register(0x3F, 0x20) {
+            Err(UartInterfaceError::RetriesExhausted) => {}
+            other => panic!("expected RetriesExhausted, got {:?}", other),
+        }
+    }
+}
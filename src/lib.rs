@@ -2,13 +2,21 @@ extern crate byteorder;
 extern crate i2cdev;
 extern crate i2csensors;
 
+pub mod interface;
+
 use byteorder::{ByteOrder, LittleEndian};
-use i2cdev::core::I2CDevice;
 use i2csensors::{Accelerometer, Gyroscope, Thermometer, Magnetometer, Vec3};
 
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::fmt;
 use std::thread;
 use std::time::Duration;
-use std::mem;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+pub use interface::{Bno055Interface, I2cInterface, SerialPort, UartInterface, UartInterfaceError};
 
 pub const BNO055_DEFAULT_ADDR: u16 = 0x28;
 pub const BNO055_ALTERNATE_ADDR: u16 = 0x29;
@@ -98,6 +106,32 @@ pub const BNO055_TEMP_SOURCE: u8 = 0x40;
 pub const BNO055_AXIS_MAP_CONFIG: u8 = 0x41;
 pub const BNO055_AXIS_MAP_SIGN: u8 = 0x42;
 
+/// Page 1 register: accelerometer range/bandwidth/operation mode, see section 4.3.63
+pub const BNO055_ACC_CONFIG: u8 = 0x08;
+/// Page 1 register: magnetometer output data rate/power/operation mode, see section 4.3.63
+pub const BNO055_MAG_CONFIG: u8 = 0x09;
+/// Page 1 register: gyroscope bandwidth/range, see section 4.3.63
+pub const BNO055_GYR_CONFIG_0: u8 = 0x0A;
+/// Page 1 register: gyroscope power mode, see section 4.3.63
+pub const BNO055_GYR_CONFIG_1: u8 = 0x0B;
+
+/// Page 1 register: interrupt mask, routes a source to the INT pin, see section 4.3.56
+pub const BNO055_INT_MSK: u8 = 0x0F;
+/// Page 1 register: interrupt enable, see section 4.3.57
+pub const BNO055_INT_EN: u8 = 0x10;
+/// Page 1 register: accelerometer any-motion threshold, see section 4.3.58
+pub const BNO055_ACC_AM_THRES: u8 = 0x11;
+/// Page 1 register: accelerometer any-motion duration and high-g/any-motion axis enables, see section 4.3.59
+pub const BNO055_ACC_INT_SETTINGS: u8 = 0x12;
+/// Page 1 register: accelerometer high-g duration, see section 4.3.60
+pub const BNO055_ACC_HG_DURATION: u8 = 0x13;
+/// Page 1 register: accelerometer high-g threshold, see section 4.3.60
+pub const BNO055_ACC_HG_THRES: u8 = 0x14;
+/// Page 1 register: accelerometer no/slow-motion threshold, see section 4.3.61
+pub const BNO055_ACC_NM_THRES: u8 = 0x15;
+/// Page 1 register: accelerometer no/slow-motion duration and mode select, see section 4.3.61
+pub const BNO055_ACC_NM_SET: u8 = 0x16;
+
 pub const BNO055_ACC_OFFSET_X_LSB: u8 = 0x55;
 pub const BNO055_ACC_OFFSET_X_MSB: u8 = 0x56;
 pub const BNO055_ACC_OFFSET_Y_LSB: u8 = 0x57;
@@ -136,6 +170,23 @@ pub enum BNO055SystemStatusCode {
     RunningWithoutFusion = 6,
 }
 
+impl TryFrom<u8> for BNO055SystemStatusCode {
+    type Error = u8;
+
+    fn try_from(byte: u8) -> Result<Self, u8> {
+        match byte {
+            0 => Ok(BNO055SystemStatusCode::SystemIdle),
+            1 => Ok(BNO055SystemStatusCode::SystemError),
+            2 => Ok(BNO055SystemStatusCode::InitPeripherals),
+            3 => Ok(BNO055SystemStatusCode::SystemInit),
+            4 => Ok(BNO055SystemStatusCode::Executing),
+            5 => Ok(BNO055SystemStatusCode::Running),
+            6 => Ok(BNO055SystemStatusCode::RunningWithoutFusion),
+            _ => Err(byte),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(u8)]
 pub enum BNO055SystemErrorCode {
@@ -152,6 +203,68 @@ pub enum BNO055SystemErrorCode {
     SensorConfig = 10,
 }
 
+impl TryFrom<u8> for BNO055SystemErrorCode {
+    type Error = u8;
+
+    fn try_from(byte: u8) -> Result<Self, u8> {
+        match byte {
+            0 => Ok(BNO055SystemErrorCode::None),
+            1 => Ok(BNO055SystemErrorCode::PeripheralInit),
+            2 => Ok(BNO055SystemErrorCode::SystemInit),
+            3 => Ok(BNO055SystemErrorCode::SelfTest),
+            4 => Ok(BNO055SystemErrorCode::RegisterMapValue),
+            5 => Ok(BNO055SystemErrorCode::RegisterMapAddress),
+            6 => Ok(BNO055SystemErrorCode::RegisterMapWrite),
+            7 => Ok(BNO055SystemErrorCode::LowPowerModeNotAvail),
+            8 => Ok(BNO055SystemErrorCode::AccelPowerModeNotAvail),
+            9 => Ok(BNO055SystemErrorCode::FusionAlgoConfig),
+            10 => Ok(BNO055SystemErrorCode::SensorConfig),
+            _ => Err(byte),
+        }
+    }
+}
+
+/// Errors produced by [BNO055](struct.BNO055.html) methods: either the
+/// underlying [Bno055Interface](interface/trait.Bno055Interface.html)
+/// failed, or a register held a value the driver couldn't decode.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// The underlying interface returned an error
+    Interface(E),
+    /// CHIP_ID didn't match [BNO055_ID](constant.BNO055_ID.html); likely a
+    /// mis-wired bus or the wrong sensor
+    InvalidChipId(u8),
+    /// A status/error register held a value with no known enum variant
+    UnknownStatusCode(u8),
+    /// A calibration profile wasn't exactly
+    /// [BNO055_CALIBRATION_SIZE](constant.BNO055_CALIBRATION_SIZE.html) bytes
+    InvalidCalibrationLength(usize),
+    /// Reading or writing a calibration profile file failed
+    Io(std::io::Error),
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Interface(e)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Interface(ref e) => write!(f, "interface error: {}", e),
+            Error::InvalidChipId(id) => write!(f, "unexpected chip id: {:#04x}", id),
+            Error::UnknownStatusCode(code) => write!(f, "unknown status/error code: {:#04x}", code),
+            Error::InvalidCalibrationLength(len) => {
+                write!(f, "calibration profile was {} bytes, expected {}", len, BNO055_CALIBRATION_SIZE)
+            }
+            Error::Io(ref e) => write!(f, "i/o error: {}", e),
+        }
+    }
+}
+
+impl<E: StdError> StdError for Error<E> {}
+
 #[derive(Debug)]
 pub struct BNO055SystemStatus {
     status: BNO055SystemStatusCode,
@@ -177,6 +290,74 @@ pub struct BNO055CalibrationStatus {
     pub mag: bool,
 }
 
+/// The 22-byte sensor offset/radius calibration profile read from/written to
+/// ACC_OFFSET_X_LSB (0x55) onward, see section 3.6.4
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BNO055Calibration {
+    pub acc_offset_x: i16,
+    pub acc_offset_y: i16,
+    pub acc_offset_z: i16,
+    pub mag_offset_x: i16,
+    pub mag_offset_y: i16,
+    pub mag_offset_z: i16,
+    pub gyr_offset_x: i16,
+    pub gyr_offset_y: i16,
+    pub gyr_offset_z: i16,
+    pub acc_radius: i16,
+    pub mag_radius: i16,
+}
+
+/// Size in bytes of a serialized [BNO055Calibration](struct.BNO055Calibration.html)
+pub const BNO055_CALIBRATION_SIZE: usize = 22;
+
+impl BNO055Calibration {
+    /// Serializes the profile to the 22-byte, little-endian layout the chip expects
+    pub fn to_bytes(&self) -> [u8; BNO055_CALIBRATION_SIZE] {
+        let mut buf = [0u8; BNO055_CALIBRATION_SIZE];
+        LittleEndian::write_i16(&mut buf[0..2], self.acc_offset_x);
+        LittleEndian::write_i16(&mut buf[2..4], self.acc_offset_y);
+        LittleEndian::write_i16(&mut buf[4..6], self.acc_offset_z);
+        LittleEndian::write_i16(&mut buf[6..8], self.mag_offset_x);
+        LittleEndian::write_i16(&mut buf[8..10], self.mag_offset_y);
+        LittleEndian::write_i16(&mut buf[10..12], self.mag_offset_z);
+        LittleEndian::write_i16(&mut buf[12..14], self.gyr_offset_x);
+        LittleEndian::write_i16(&mut buf[14..16], self.gyr_offset_y);
+        LittleEndian::write_i16(&mut buf[16..18], self.gyr_offset_z);
+        LittleEndian::write_i16(&mut buf[18..20], self.acc_radius);
+        LittleEndian::write_i16(&mut buf[20..22], self.mag_radius);
+        buf
+    }
+
+    /// Parses a profile from a 22-byte little-endian buffer, as read from
+    /// ACC_OFFSET_X_LSB (0x55) or from a saved calibration profile file
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, BNO055CalibrationError> {
+        if buf.len() != BNO055_CALIBRATION_SIZE {
+            return Err(BNO055CalibrationError::InvalidLength(buf.len()));
+        }
+        Ok(BNO055Calibration {
+            acc_offset_x: LittleEndian::read_i16(&buf[0..2]),
+            acc_offset_y: LittleEndian::read_i16(&buf[2..4]),
+            acc_offset_z: LittleEndian::read_i16(&buf[4..6]),
+            mag_offset_x: LittleEndian::read_i16(&buf[6..8]),
+            mag_offset_y: LittleEndian::read_i16(&buf[8..10]),
+            mag_offset_z: LittleEndian::read_i16(&buf[10..12]),
+            gyr_offset_x: LittleEndian::read_i16(&buf[12..14]),
+            gyr_offset_y: LittleEndian::read_i16(&buf[14..16]),
+            gyr_offset_z: LittleEndian::read_i16(&buf[16..18]),
+            acc_radius: LittleEndian::read_i16(&buf[18..20]),
+            mag_radius: LittleEndian::read_i16(&buf[20..22]),
+        })
+    }
+}
+
+/// Errors produced while parsing a [BNO055Calibration](struct.BNO055Calibration.html)
+#[derive(Debug, PartialEq)]
+pub enum BNO055CalibrationError {
+    /// The buffer was not exactly [BNO055_CALIBRATION_SIZE](constant.BNO055_CALIBRATION_SIZE.html)
+    /// bytes long
+    InvalidLength(usize),
+}
+
 #[derive(Debug)]
 pub struct BNO055QuaternionReading {
     pub w: f32,
@@ -200,6 +381,551 @@ pub enum BNO055PowerMode {
     Suspend = 0b10,
 }
 
+/// Acceleration unit, selected via bit0 of [BNO055_UNIT_SEL](constant.BNO055_UNIT_SEL.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055AccUnit {
+    /// 100 LSB/(m/s^2)
+    Mps2,
+    /// 1 LSB/mg
+    Mg,
+}
+
+/// Angular rate unit, selected via bit1 of [BNO055_UNIT_SEL](constant.BNO055_UNIT_SEL.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055AngularRateUnit {
+    /// 16 LSB/dps
+    Dps,
+    /// 900 LSB/rps
+    Rps,
+}
+
+/// Euler angle unit, selected via bit2 of [BNO055_UNIT_SEL](constant.BNO055_UNIT_SEL.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055EulerUnit {
+    /// 16 LSB/degree
+    Degrees,
+    /// 900 LSB/radian
+    Radians,
+}
+
+/// Temperature unit, selected via bit4 of [BNO055_UNIT_SEL](constant.BNO055_UNIT_SEL.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055TemperatureUnit {
+    /// 1 LSB/degree Celsius
+    Celsius,
+    /// 2 LSB/degree Fahrenheit
+    Fahrenheit,
+}
+
+/// Orientation (heading) convention, selected via bit7 of
+/// [BNO055_UNIT_SEL](constant.BNO055_UNIT_SEL.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055OrientationMode {
+    Windows,
+    Android,
+}
+
+/// The set of measurement units the BNO055 reports data in, see section 3.6.1
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BNO055Units {
+    pub acc: BNO055AccUnit,
+    pub gyr: BNO055AngularRateUnit,
+    pub eul: BNO055EulerUnit,
+    pub temp: BNO055TemperatureUnit,
+    pub orientation: BNO055OrientationMode,
+}
+
+impl Default for BNO055Units {
+    /// The chip's power-on-reset defaults
+    fn default() -> Self {
+        BNO055Units {
+            acc: BNO055AccUnit::Mps2,
+            gyr: BNO055AngularRateUnit::Dps,
+            eul: BNO055EulerUnit::Degrees,
+            temp: BNO055TemperatureUnit::Celsius,
+            orientation: BNO055OrientationMode::Windows,
+        }
+    }
+}
+
+impl BNO055Units {
+    fn to_byte(self) -> u8 {
+        let mut byte = 0u8;
+        if self.acc == BNO055AccUnit::Mg {
+            byte |= 1 << 0;
+        }
+        if self.gyr == BNO055AngularRateUnit::Rps {
+            byte |= 1 << 1;
+        }
+        if self.eul == BNO055EulerUnit::Radians {
+            byte |= 1 << 2;
+        }
+        if self.temp == BNO055TemperatureUnit::Fahrenheit {
+            byte |= 1 << 4;
+        }
+        if self.orientation == BNO055OrientationMode::Android {
+            byte |= 1 << 7;
+        }
+        byte
+    }
+
+    fn acc_scale(&self) -> f32 {
+        match self.acc {
+            BNO055AccUnit::Mps2 => 1.0 / 100.0,
+            BNO055AccUnit::Mg => 1.0,
+        }
+    }
+
+    fn gyr_scale(&self) -> f32 {
+        match self.gyr {
+            BNO055AngularRateUnit::Dps => 1.0 / 16.0,
+            BNO055AngularRateUnit::Rps => 1.0 / 900.0,
+        }
+    }
+
+    fn eul_scale(&self) -> f32 {
+        match self.eul {
+            BNO055EulerUnit::Degrees => 1.0 / 16.0,
+            BNO055EulerUnit::Radians => 1.0 / 900.0,
+        }
+    }
+}
+
+/// A physical axis of the chip's sensor data, used as the source axis in an
+/// [BNO055AxisRemap](struct.BNO055AxisRemap.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl BNO055Axis {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055Axis::X => 0b00,
+            BNO055Axis::Y => 0b01,
+            BNO055Axis::Z => 0b10,
+        }
+    }
+}
+
+/// Remaps the chip's physical axes onto its reported X/Y/Z axes, for mounting the
+/// board in an orientation other than the one the firmware assumes. Writes
+/// AXIS_MAP_CONFIG (0x41) and AXIS_MAP_SIGN (0x42), see section 3.4.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BNO055AxisRemap {
+    pub x_source: BNO055Axis,
+    pub y_source: BNO055Axis,
+    pub z_source: BNO055Axis,
+    pub x_negate: bool,
+    pub y_negate: bool,
+    pub z_negate: bool,
+}
+
+impl BNO055AxisRemap {
+    /// Builds a remap, returning `None` if two of the reported axes would be sourced
+    /// from the same physical axis
+    pub fn new(
+        x_source: BNO055Axis,
+        y_source: BNO055Axis,
+        z_source: BNO055Axis,
+        x_negate: bool,
+        y_negate: bool,
+        z_negate: bool,
+    ) -> Option<Self> {
+        if x_source == y_source || y_source == z_source || x_source == z_source {
+            return None;
+        }
+        Some(BNO055AxisRemap {
+            x_source,
+            y_source,
+            z_source,
+            x_negate,
+            y_negate,
+            z_negate,
+        })
+    }
+
+    fn to_bytes(self) -> (u8, u8) {
+        let config = self.x_source.to_bits() | (self.y_source.to_bits() << 2) |
+            (self.z_source.to_bits() << 4);
+        let sign = ((self.x_negate as u8) << 2) | ((self.y_negate as u8) << 1) |
+            (self.z_negate as u8);
+        (config, sign)
+    }
+}
+
+/// The eight standard board-placement orientations from the datasheet (section 3.4,
+/// figure 3-6), for picking a mounting orientation without hand-assembling bitfields
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BNO055AxisRemapPreset {
+    P0,
+    /// The chip's default orientation
+    P1,
+    P2,
+    P3,
+    P4,
+    P5,
+    P6,
+    P7,
+}
+
+impl BNO055AxisRemapPreset {
+    pub fn remap(&self) -> BNO055AxisRemap {
+        use BNO055Axis::*;
+        match *self {
+            BNO055AxisRemapPreset::P0 => {
+                BNO055AxisRemap::new(Y, X, Z, true, false, false).unwrap()
+            }
+            BNO055AxisRemapPreset::P1 => {
+                BNO055AxisRemap::new(X, Y, Z, false, false, false).unwrap()
+            }
+            BNO055AxisRemapPreset::P2 => {
+                BNO055AxisRemap::new(X, Y, Z, true, true, false).unwrap()
+            }
+            BNO055AxisRemapPreset::P3 => {
+                BNO055AxisRemap::new(Y, X, Z, false, true, false).unwrap()
+            }
+            BNO055AxisRemapPreset::P4 => {
+                BNO055AxisRemap::new(X, Y, Z, false, true, true).unwrap()
+            }
+            BNO055AxisRemapPreset::P5 => {
+                BNO055AxisRemap::new(Y, X, Z, false, false, true).unwrap()
+            }
+            BNO055AxisRemapPreset::P6 => {
+                BNO055AxisRemap::new(Y, X, Z, true, true, true).unwrap()
+            }
+            BNO055AxisRemapPreset::P7 => {
+                BNO055AxisRemap::new(X, Y, Z, true, false, true).unwrap()
+            }
+        }
+    }
+}
+
+/// Accelerometer full-scale range, written to bits[1:0] of ACC_CONFIG (0x08). Only
+/// effective in non-fusion modes; the fusion modes lock this to their own value.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BNO055AccRange {
+    G2,
+    /// The chip's default range
+    #[default]
+    G4,
+    G8,
+    G16,
+}
+
+impl BNO055AccRange {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055AccRange::G2 => 0b00,
+            BNO055AccRange::G4 => 0b01,
+            BNO055AccRange::G8 => 0b10,
+            BNO055AccRange::G16 => 0b11,
+        }
+    }
+
+    fn g(self) -> f32 {
+        match self {
+            BNO055AccRange::G2 => 2.0,
+            BNO055AccRange::G4 => 4.0,
+            BNO055AccRange::G8 => 8.0,
+            BNO055AccRange::G16 => 16.0,
+        }
+    }
+}
+
+/// Accelerometer low-pass filter bandwidth, written to bits[4:2] of ACC_CONFIG (0x08)
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BNO055AccBandwidth {
+    Hz7_81,
+    Hz15_63,
+    Hz31_25,
+    /// The chip's default bandwidth
+    #[default]
+    Hz62_5,
+    Hz125,
+    Hz250,
+    Hz500,
+    Hz1000,
+}
+
+impl BNO055AccBandwidth {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055AccBandwidth::Hz7_81 => 0b000,
+            BNO055AccBandwidth::Hz15_63 => 0b001,
+            BNO055AccBandwidth::Hz31_25 => 0b010,
+            BNO055AccBandwidth::Hz62_5 => 0b011,
+            BNO055AccBandwidth::Hz125 => 0b100,
+            BNO055AccBandwidth::Hz250 => 0b101,
+            BNO055AccBandwidth::Hz500 => 0b110,
+            BNO055AccBandwidth::Hz1000 => 0b111,
+        }
+    }
+}
+
+/// Gyroscope full-scale range, written to bits[5:3] of GYR_CONFIG_0 (0x0A). Only
+/// effective in non-fusion modes; the fusion modes lock this to their own value.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BNO055GyrRange {
+    /// The chip's default range
+    #[default]
+    Dps2000,
+    Dps1000,
+    Dps500,
+    Dps250,
+    Dps125,
+}
+
+impl BNO055GyrRange {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055GyrRange::Dps2000 => 0b000,
+            BNO055GyrRange::Dps1000 => 0b001,
+            BNO055GyrRange::Dps500 => 0b010,
+            BNO055GyrRange::Dps250 => 0b011,
+            BNO055GyrRange::Dps125 => 0b100,
+        }
+    }
+
+    fn dps(self) -> f32 {
+        match self {
+            BNO055GyrRange::Dps2000 => 2000.0,
+            BNO055GyrRange::Dps1000 => 1000.0,
+            BNO055GyrRange::Dps500 => 500.0,
+            BNO055GyrRange::Dps250 => 250.0,
+            BNO055GyrRange::Dps125 => 125.0,
+        }
+    }
+}
+
+/// Gyroscope low-pass filter bandwidth, written to bits[2:0] of GYR_CONFIG_0 (0x0A)
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BNO055GyrBandwidth {
+    Hz523,
+    Hz230,
+    Hz116,
+    Hz47,
+    Hz23,
+    Hz12,
+    Hz64,
+    /// The chip's default bandwidth
+    #[default]
+    Hz32,
+}
+
+impl BNO055GyrBandwidth {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055GyrBandwidth::Hz523 => 0b000,
+            BNO055GyrBandwidth::Hz230 => 0b001,
+            BNO055GyrBandwidth::Hz116 => 0b010,
+            BNO055GyrBandwidth::Hz47 => 0b011,
+            BNO055GyrBandwidth::Hz23 => 0b100,
+            BNO055GyrBandwidth::Hz12 => 0b101,
+            BNO055GyrBandwidth::Hz64 => 0b110,
+            BNO055GyrBandwidth::Hz32 => 0b111,
+        }
+    }
+}
+
+/// Gyroscope power mode, written to bits[2:0] of GYR_CONFIG_1 (0x0B). Distinct from
+/// [BNO055PowerMode](enum.BNO055PowerMode.html), which is the overall system power mode.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BNO055GyrPowerMode {
+    /// The chip's default power mode
+    #[default]
+    Normal,
+    FastPowerUp,
+    DeepSuspend,
+    Suspend,
+    AdvancedPowerSave,
+}
+
+impl BNO055GyrPowerMode {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055GyrPowerMode::Normal => 0b000,
+            BNO055GyrPowerMode::FastPowerUp => 0b001,
+            BNO055GyrPowerMode::DeepSuspend => 0b010,
+            BNO055GyrPowerMode::Suspend => 0b011,
+            BNO055GyrPowerMode::AdvancedPowerSave => 0b100,
+        }
+    }
+}
+
+/// Magnetometer output data rate, written to bits[2:0] of MAG_CONFIG (0x09)
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BNO055MagRate {
+    Hz2,
+    Hz6,
+    Hz8,
+    Hz10,
+    Hz15,
+    /// The chip's default rate
+    #[default]
+    Hz20,
+    Hz25,
+    Hz30,
+}
+
+impl BNO055MagRate {
+    fn to_bits(self) -> u8 {
+        match self {
+            BNO055MagRate::Hz2 => 0b000,
+            BNO055MagRate::Hz6 => 0b001,
+            BNO055MagRate::Hz8 => 0b010,
+            BNO055MagRate::Hz10 => 0b011,
+            BNO055MagRate::Hz15 => 0b100,
+            BNO055MagRate::Hz20 => 0b101,
+            BNO055MagRate::Hz25 => 0b110,
+            BNO055MagRate::Hz30 => 0b111,
+        }
+    }
+}
+
+/// The page-1 sensor configuration available in non-fusion (AMG) modes: accelerometer
+/// and gyroscope range/bandwidth/power mode and magnetometer output data rate. The
+/// fusion modes, see
+/// [is_fusion_mode](enum.BNO055OperationMode.html#method.is_fusion_mode), lock these
+/// down.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct BNO055AmgConfig {
+    pub acc_range: BNO055AccRange,
+    pub acc_bandwidth: BNO055AccBandwidth,
+    pub gyr_range: BNO055GyrRange,
+    pub gyr_bandwidth: BNO055GyrBandwidth,
+    pub gyr_power_mode: BNO055GyrPowerMode,
+    pub mag_rate: BNO055MagRate,
+}
+
+impl BNO055AmgConfig {
+    fn acc_config_bits(&self) -> u8 {
+        self.acc_range.to_bits() | (self.acc_bandwidth.to_bits() << 2)
+    }
+
+    fn gyr_config_0_bits(&self) -> u8 {
+        self.gyr_bandwidth.to_bits() | (self.gyr_range.to_bits() << 3)
+    }
+}
+
+/// Which hardware interrupt sources generate an interrupt and are routed to
+/// the INT pin, written to INT_EN (0x10) and INT_MSK (0x0F). See section
+/// 4.3.56/4.3.57. A source left `false` is neither enabled nor routed to the
+/// INT pin.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct BNO055InterruptSources {
+    pub acc_any_motion: bool,
+    pub acc_high_g: bool,
+    pub acc_no_motion: bool,
+    pub gyro_any_motion: bool,
+    pub gyro_high_rate: bool,
+}
+
+impl BNO055InterruptSources {
+    fn to_bits(self) -> u8 {
+        let mut bits = 0u8;
+        if self.acc_no_motion {
+            bits |= 1 << 7;
+        }
+        if self.acc_any_motion {
+            bits |= 1 << 6;
+        }
+        if self.acc_high_g {
+            bits |= 1 << 5;
+        }
+        if self.gyro_high_rate {
+            bits |= 1 << 3;
+        }
+        if self.gyro_any_motion {
+            bits |= 1 << 2;
+        }
+        bits
+    }
+}
+
+/// Accelerometer any-motion/no-motion/slow-motion/high-g thresholds and
+/// durations, written to ACC_AM_THRES (0x11), ACC_INT_Settings (0x12),
+/// ACC_HG_DURATION (0x13), ACC_HG_THRES (0x14), ACC_NM_THRES (0x15), and
+/// ACC_NM_SET (0x16). See section 4.3.58-4.3.61. Threshold units depend on
+/// the configured [BNO055AccRange](enum.BNO055AccRange.html).
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct BNO055AccMotionConfig {
+    /// Any-motion threshold (ACC_AM_THRES)
+    pub any_motion_threshold: u8,
+    /// Number of consecutive slope data points above the any-motion
+    /// threshold required to trigger the interrupt, encoded 0-3 for 1-4
+    /// points (AM_Dur in ACC_INT_Settings)
+    pub any_motion_duration: u8,
+    /// Enables the any-motion interrupt on the X axis (ACC_INT_Settings)
+    pub any_motion_x: bool,
+    /// Enables the any-motion interrupt on the Y axis (ACC_INT_Settings)
+    pub any_motion_y: bool,
+    /// Enables the any-motion interrupt on the Z axis (ACC_INT_Settings)
+    pub any_motion_z: bool,
+    /// Enables the high-g interrupt on the X axis (ACC_INT_Settings)
+    pub high_g_x: bool,
+    /// Enables the high-g interrupt on the Y axis (ACC_INT_Settings)
+    pub high_g_y: bool,
+    /// Enables the high-g interrupt on the Z axis (ACC_INT_Settings)
+    pub high_g_z: bool,
+    /// High-g duration (ACC_HG_DURATION)
+    pub high_g_duration: u8,
+    /// High-g threshold (ACC_HG_THRES)
+    pub high_g_threshold: u8,
+    /// No/slow-motion threshold (ACC_NM_THRES)
+    pub no_motion_threshold: u8,
+    /// No/slow-motion duration setting, see the delay table in section
+    /// 4.3.61 (bits[6:1] of ACC_NM_SET)
+    pub no_motion_duration: u8,
+    /// Selects slow-motion mode rather than no-motion mode for the
+    /// no/slow-motion interrupt (bottom bit of ACC_NM_SET)
+    pub slow_motion: bool,
+}
+
+impl BNO055AccMotionConfig {
+    fn nm_set_bits(&self) -> u8 {
+        ((self.no_motion_duration & 0b0011_1111) << 1) | (self.slow_motion as u8)
+    }
+
+    fn int_settings_bits(&self) -> u8 {
+        let mut bits = self.any_motion_duration & 0b11;
+        if self.any_motion_x {
+            bits |= 1 << 2;
+        }
+        if self.any_motion_y {
+            bits |= 1 << 3;
+        }
+        if self.any_motion_z {
+            bits |= 1 << 4;
+        }
+        if self.high_g_x {
+            bits |= 1 << 5;
+        }
+        if self.high_g_y {
+            bits |= 1 << 6;
+        }
+        if self.high_g_z {
+            bits |= 1 << 7;
+        }
+        bits
+    }
+}
+
+/// The latched interrupt flags decoded from INT_STA (0x37). Each flag stays
+/// set until cleared with [clear_interrupts](struct.BNO055.html#method.clear_interrupts).
+#[derive(Debug)]
+pub struct BNO055InterruptStatus {
+    /// Accelerometer/gyroscope data-ready interrupt
+    pub acc_bsx: bool,
+    pub acc_high_g: bool,
+    pub acc_am: bool,
+    pub acc_nm: bool,
+    pub gyro_am: bool,
+    pub gyro_high_rate: bool,
+}
+
 #[derive(Copy, Clone, PartialEq)]
 #[repr(u8)]
 pub enum BNO055OperationMode {
@@ -218,50 +944,75 @@ pub enum BNO055OperationMode {
     Ndof = 0b1100,
 }
 
+impl BNO055OperationMode {
+    /// Whether this mode runs the sensor fusion engine. See
+    /// [BNO055AmgConfig](struct.BNO055AmgConfig.html) for what the fusion modes lock down.
+    pub fn is_fusion_mode(&self) -> bool {
+        match *self {
+            BNO055OperationMode::IMU |
+            BNO055OperationMode::Compass |
+            BNO055OperationMode::M4G |
+            BNO055OperationMode::NdofFmcOff |
+            BNO055OperationMode::Ndof => true,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
-pub struct BNO055<T: I2CDevice + Sized> {
-    pub i2cdev: T,
+pub struct BNO055<I: Bno055Interface> {
+    pub interface: I,
     pub mode: BNO055OperationMode,
+    units: BNO055Units,
+    acc_range: BNO055AccRange,
+    gyr_range: BNO055GyrRange,
 }
 
-impl<T> BNO055<T>
+impl<I> BNO055<I>
 where
-    T: I2CDevice + Sized,
+    I: Bno055Interface,
 {
-    pub fn new(mut i2cdev: T) -> Result<Self, T::Error> {
-        let chip_id = i2cdev.smbus_read_byte_data(BNO055_CHIP_ID)?;
+    pub fn new(mut interface: I) -> Result<Self, Error<I::Error>> {
+        let chip_id = interface.read_register(BNO055_CHIP_ID)?;
         if chip_id != BNO055_ID {
-            // TODO: Do correct error handling
-            panic!("BNO055_CHIP_ID was not valid!");
+            return Err(Error::InvalidChipId(chip_id));
         }
 
         let mut bno = BNO055 {
-            i2cdev: i2cdev,
+            interface: interface,
             mode: BNO055OperationMode::ConfigMode,
+            units: BNO055Units::default(),
+            acc_range: BNO055AccRange::default(),
+            gyr_range: BNO055GyrRange::default(),
         };
         bno.set_mode(BNO055OperationMode::ConfigMode)?;
         bno.set_page(BNO055RegisterPage::Page0)?;
         bno.reset()?;
         bno.set_power_mode(BNO055PowerMode::Normal)?;
-        bno.i2cdev.smbus_write_byte_data(BNO055_SYS_TRIGGER, 0x0)?;
+        bno.interface.write_register(BNO055_SYS_TRIGGER, 0x0)?;
+        bno.set_units(BNO055Units::default())?;
 
         Ok(bno)
     }
 
     /// Reset the BNO055, initializing the register map to default values
     /// More in section 3.2
-    pub fn reset(&mut self) -> Result<(), T::Error> {
-        Ok(self.i2cdev.smbus_write_byte_data(BNO055_SYS_TRIGGER, 0x20)?)
+    pub fn reset(&mut self) -> Result<(), Error<I::Error>> {
+        Ok(self.interface.write_register(
+            BNO055_SYS_TRIGGER,
+            0x20,
+        )?)
     }
 
     /// Sets the operating mode, see [BNO055OperationMode](enum.BNO055OperationMode.html)
     /// More in section 3.3
-    pub fn set_mode(&mut self, mode: BNO055OperationMode) -> Result<(), T::Error> {
+    pub fn set_mode(&mut self, mode: BNO055OperationMode) -> Result<(), Error<I::Error>> {
         if self.mode != mode {
-            self.i2cdev.smbus_write_byte_data(
-                BNO055_OPR_MODE,
-                mode as u8,
-            )?;
+            self.interface.write_register(
+            BNO055_OPR_MODE,
+            mode as u8,
+        )?;
+            self.mode = mode;
 
             // Table 3-6 says 19ms to switch to CONFIG_MODE
             thread::sleep(Duration::from_millis(19));
@@ -269,10 +1020,52 @@ where
         Ok(())
     }
 
-    pub fn set_external_crystal(&mut self, ext: bool) -> Result<(), T::Error> {
+    /// Sets the measurement units used for subsequent readings, see
+    /// [BNO055Units](struct.BNO055Units.html). Writes UNIT_SEL (0x3B).
+    /// More in section 3.6.1
+    pub fn set_units(&mut self, units: BNO055Units) -> Result<(), Error<I::Error>> {
+        self.interface.write_register(
+            BNO055_UNIT_SEL,
+            units.to_byte(),
+        )?;
+        self.units = units;
+        Ok(())
+    }
+
+    /// Gets the measurement units currently in use, see
+    /// [BNO055Units](struct.BNO055Units.html)
+    pub fn get_units(&self) -> BNO055Units {
+        self.units
+    }
+
+    /// The raw accelerometer reading's LSB-per-unit scale depends on both the
+    /// selected unit and the range set via [set_amg_config](fn.set_amg_config.html):
+    /// halving the range doubles the counts per unit. Fusion modes ignore
+    /// `set_amg_config` and lock the chip to its default range, so `acc_range`
+    /// (which only tracks the last AMG-mode write) is not applied there.
+    fn effective_acc_scale(&self) -> f32 {
+        if self.mode.is_fusion_mode() {
+            return self.units.acc_scale();
+        }
+        self.units.acc_scale() * (self.acc_range.g() / BNO055AccRange::default().g())
+    }
+
+    /// The raw gyroscope reading's LSB-per-unit scale depends on both the selected
+    /// unit and the range set via [set_amg_config](fn.set_amg_config.html): halving
+    /// the range doubles the counts per unit. Fusion modes ignore
+    /// `set_amg_config` and lock the chip to its default range, so `gyr_range`
+    /// (which only tracks the last AMG-mode write) is not applied there.
+    fn effective_gyr_scale(&self) -> f32 {
+        if self.mode.is_fusion_mode() {
+            return self.units.gyr_scale();
+        }
+        self.units.gyr_scale() * (self.gyr_range.dps() / BNO055GyrRange::default().dps())
+    }
+
+    pub fn set_external_crystal(&mut self, ext: bool) -> Result<(), Error<I::Error>> {
         let prev = self.mode;
         self.set_mode(BNO055OperationMode::ConfigMode)?;
-        self.i2cdev.smbus_write_byte_data(
+        self.interface.write_register(
             BNO055_SYS_TRIGGER,
             if ext { 0x80 } else { 0x00 },
         )?;
@@ -282,8 +1075,8 @@ where
 
     /// Sets the power mode, see [BNO055PowerMode](enum.BNO055PowerMode.html)
     /// More in section 3.2
-    pub fn set_power_mode(&mut self, mode: BNO055PowerMode) -> Result<(), T::Error> {
-        self.i2cdev.smbus_write_byte_data(
+    pub fn set_power_mode(&mut self, mode: BNO055PowerMode) -> Result<(), Error<I::Error>> {
+        self.interface.write_register(
             BNO055_PWR_MODE,
             mode as u8,
         )?;
@@ -292,8 +1085,8 @@ where
 
     /// Sets the register page
     /// More in section 4.2
-    pub fn set_page(&mut self, page: BNO055RegisterPage) -> Result<(), T::Error> {
-        self.i2cdev.smbus_write_byte_data(
+    pub fn set_page(&mut self, page: BNO055RegisterPage) -> Result<(), Error<I::Error>> {
+        self.interface.write_register(
             BNO055_PAGE_ID,
             page as u8,
         )?;
@@ -302,11 +1095,9 @@ where
 
     /// Gets a quaternion reading from the BNO055
     /// Must be in a valid operating mode
-    pub fn get_quaternion(&mut self) -> Result<BNO055QuaternionReading, T::Error> {
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_QUA_DATA_W_LSB,
-            8,
-        )?;
+    pub fn get_quaternion(&mut self) -> Result<BNO055QuaternionReading, Error<I::Error>> {
+        let mut buf = [0u8; 8];
+        self.interface.read_registers(BNO055_QUA_DATA_W_LSB, &mut buf)?;
         let w = LittleEndian::read_i16(&buf[0..2]);
         let x = LittleEndian::read_i16(&buf[2..4]);
         let y = LittleEndian::read_i16(&buf[4..6]);
@@ -323,9 +1114,10 @@ where
 
     /// Gets the revision of software, bootloader, accelerometer, magnetometer, and gyroscope of
     /// the BNO055
-    pub fn get_revision(&mut self) -> Result<BNO055Revision, T::Error> {
+    pub fn get_revision(&mut self) -> Result<BNO055Revision, Error<I::Error>> {
         // TODO: Check page
-        let buf = self.i2cdev.smbus_read_i2c_block_data(BNO055_ACC_ID, 6)?;
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_ACC_ID, &mut buf)?;
         Ok(BNO055Revision {
             software: LittleEndian::read_u16(&buf[3..5]),
             bootloader: buf[5],
@@ -336,84 +1128,267 @@ where
     }
 
     /// Get the system status
-    pub fn get_system_status(&mut self, run: bool) -> Result<BNO055SystemStatus, T::Error> {
+    pub fn get_system_status(&mut self, run: bool) -> Result<BNO055SystemStatus, Error<I::Error>> {
         let selftest = if run {
             let prev = self.mode;
             self.set_mode(BNO055OperationMode::ConfigMode)?;
 
-            let sys_trigger = self.i2cdev.smbus_read_byte_data(BNO055_SYS_TRIGGER)?;
-            self.i2cdev.smbus_write_byte_data(
+            let sys_trigger = self.interface.read_register(BNO055_SYS_TRIGGER)?;
+            self.interface.write_register(
                 BNO055_SYS_TRIGGER,
                 sys_trigger | 0x1,
             )?;
 
             thread::sleep(Duration::from_secs(1));
 
-            let result = self.i2cdev.smbus_read_byte_data(BNO055_ST_RESULT)?;
+            let result = self.interface.read_register(BNO055_ST_RESULT)?;
             self.set_mode(prev)?;
             Some(result)
         } else {
             None
         };
 
+        let status_byte = self.interface.read_register(BNO055_SYS_STATUS)?;
+        let error_byte = self.interface.read_register(BNO055_SYS_ERR)?;
+
         Ok(BNO055SystemStatus {
-            status: unsafe { mem::transmute(self.i2cdev.smbus_read_byte_data(BNO055_SYS_STATUS)?) },
-            error: unsafe { mem::transmute(self.i2cdev.smbus_read_byte_data(BNO055_SYS_ERR)?) },
+            status: BNO055SystemStatusCode::try_from(status_byte).map_err(Error::UnknownStatusCode)?,
+            error: BNO055SystemErrorCode::try_from(error_byte).map_err(Error::UnknownStatusCode)?,
             selftest,
         })
     }
 
     /// Get the calibration status
-    pub fn get_calibration_status(&mut self) -> Result<BNO055CalibrationStatus, T::Error> {
-        let status = self.i2cdev.smbus_read_byte_data(BNO055_CALIB_STAT)?;
+    pub fn get_calibration_status(&mut self) -> Result<BNO055CalibrationStatus, Error<I::Error>> {
+        let status = self.interface.read_register(BNO055_CALIB_STAT)?;
         let sys = (status & 0b11000000) >> 6 == 0b11;
-        let gyr = (status & 0b00110000) >> 6 == 0b11;
-        let acc = (status & 0b00001100) >> 6 == 0b11;
-        let mag = (status & 0b00000011) >> 6 == 0b11;
+        let gyr = (status & 0b00110000) >> 4 == 0b11;
+        let acc = (status & 0b00001100) >> 2 == 0b11;
+        let mag = status & 0b00000011 == 0b11;
 
         Ok(BNO055CalibrationStatus { sys, gyr, acc, mag })
     }
 
-    // TODO: Make this calibration a struct
-    /// Get the calibration details. Can be used with [set_calibration](fn.set_calibration.html) to
-    /// load previous configs.
-    pub fn get_calibration(&mut self) -> Result<Vec<u8>, T::Error> {
+    /// Get the calibration profile. Can be used with
+    /// [set_calibration](fn.set_calibration.html) to load previous configs. Switches to
+    /// CONFIG mode for the read; the previous mode is restored afterward.
+    pub fn get_calibration(&mut self) -> Result<BNO055Calibration, Error<I::Error>> {
         let prev = self.mode;
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_ACC_OFFSET_X_LSB,
-            22,
-        );
+        self.set_mode(BNO055OperationMode::ConfigMode)?;
+        let mut buf = [0u8; BNO055_CALIBRATION_SIZE];
+        self.interface.read_registers(BNO055_ACC_OFFSET_X_LSB, &mut buf)?;
+        self.set_mode(prev)?;
+        Ok(BNO055Calibration::from_bytes(&buf).expect(
+            "read_registers filled exactly BNO055_CALIBRATION_SIZE bytes",
+        ))
+    }
+
+    /// Set the calibration profile. Can be used with
+    /// [get_calibration](fn.get_calibration.html) to load previous configs. Switches to
+    /// CONFIG mode for the write; the previous mode is restored afterward.
+    pub fn set_calibration(&mut self, calibration: BNO055Calibration) -> Result<(), Error<I::Error>> {
+        let prev = self.mode;
+        self.set_mode(BNO055OperationMode::ConfigMode)?;
+        self.interface.write_registers(BNO055_ACC_OFFSET_X_LSB, &calibration.to_bytes())?;
         self.set_mode(prev)?;
-        return buf;
+        Ok(())
+    }
+
+    /// Blocks until [get_calibration_status](fn.get_calibration_status.html) reports
+    /// every subsystem as fully calibrated, polling every 100ms. Intended to be
+    /// called before [save_calibration_profile](fn.save_calibration_profile.html) so
+    /// the dumped profile is actually usable.
+    pub fn wait_for_full_calibration(&mut self) -> Result<(), Error<I::Error>> {
+        loop {
+            let status = self.get_calibration_status()?;
+            if status.sys && status.gyr && status.acc && status.mag {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
     }
 
-    // TODO: Use a calibration struct, check for buf length
-    /// Set the calibration details. Can be used with [get_calibration](fn.get_calibration.html) to
-    /// load previous configs.
-    pub fn set_calibration(&mut self, buf: Vec<u8>) -> Result<(), T::Error> {
+    /// Builds a default calibration file name from `label` and the sensor's SW
+    /// revision. The BNO055 has no UID register: ACC_ID/MAG_ID/GYR_ID are fixed
+    /// hardware-identifier constants that read the same on every unit, and
+    /// SW_REV_ID is just the firmware version most off-the-shelf units share, so
+    /// none of them can tell two physical sensors apart. Pass something that
+    /// does, e.g. the I2C address or bus number, or any label you choose, so
+    /// profiles for different sensors don't clobber each other.
+    pub fn default_calibration_profile_path(&mut self, label: &str) -> Result<PathBuf, Error<I::Error>> {
+        let rev = self.get_revision()?;
+        Ok(PathBuf::from(format!("bno055_{}_{:04x}.cal", label, rev.software)))
+    }
+
+    /// Reads the calibration profile and writes it to `path` as 22 raw bytes
+    pub fn save_calibration_profile<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), Error<I::Error>> {
+        let calibration = self.get_calibration()?;
+        let mut file = File::create(path).map_err(Error::Io)?;
+        file.write_all(&calibration.to_bytes()).map_err(Error::Io)?;
+        Ok(())
+    }
+
+    /// Reads a 22-byte calibration profile from `path` and writes it to the chip
+    pub fn load_calibration_profile<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), Error<I::Error>> {
+        let mut buf = Vec::new();
+        let mut file = File::open(path).map_err(Error::Io)?;
+        file.read_to_end(&mut buf).map_err(Error::Io)?;
+        let calibration = BNO055Calibration::from_bytes(&buf).map_err(|BNO055CalibrationError::InvalidLength(len)| {
+            Error::InvalidCalibrationLength(len)
+        })?;
+        self.set_calibration(calibration)?;
+        Ok(())
+    }
+
+    /// Remaps the chip's physical axes, see
+    /// [BNO055AxisRemap](struct.BNO055AxisRemap.html) and
+    /// [BNO055AxisRemapPreset](enum.BNO055AxisRemapPreset.html). Switches to CONFIG
+    /// mode for the write; the previous mode is restored afterward.
+    pub fn set_axis_remap(&mut self, remap: BNO055AxisRemap) -> Result<(), Error<I::Error>> {
         let prev = self.mode;
-        self.i2cdev.smbus_write_block_data(
-            BNO055_ACC_OFFSET_X_LSB,
-            &buf,
+        self.set_mode(BNO055OperationMode::ConfigMode)?;
+        let (config, sign) = remap.to_bytes();
+        self.interface.write_register(
+            BNO055_AXIS_MAP_CONFIG,
+            config,
+        )?;
+        self.interface.write_register(
+            BNO055_AXIS_MAP_SIGN,
+            sign,
         )?;
         self.set_mode(prev)?;
         Ok(())
     }
 
-    // TODO: Axis remap
+    /// Sets the accelerometer/gyroscope range and bandwidth and the magnetometer
+    /// output data rate, see [BNO055AmgConfig](struct.BNO055AmgConfig.html). These
+    /// registers live on page 1 and are switched to/from via
+    /// [set_page](fn.set_page.html). This is a no-op when
+    /// [mode](struct.BNO055.html#structfield.mode) is a fusion mode, since the chip
+    /// ignores them there.
+    pub fn set_amg_config(&mut self, config: BNO055AmgConfig) -> Result<(), Error<I::Error>> {
+        if self.mode.is_fusion_mode() {
+            return Ok(());
+        }
 
-    /// Get euler angle representation of orientation.
-    /// The `x` component is the heading, `y` is the roll, `z` is pitch, all in radians
-    pub fn get_euler(&mut self) -> Result<Vec3, T::Error> {
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_EUL_HEADING_LSB,
-            6,
+        self.set_page(BNO055RegisterPage::Page1)?;
+        self.interface.write_register(
+            BNO055_ACC_CONFIG,
+            config.acc_config_bits(),
+        )?;
+        self.interface.write_register(
+            BNO055_GYR_CONFIG_0,
+            config.gyr_config_0_bits(),
+        )?;
+        self.interface.write_register(
+            BNO055_GYR_CONFIG_1,
+            config.gyr_power_mode.to_bits(),
+        )?;
+        self.interface.write_register(
+            BNO055_MAG_CONFIG,
+            config.mag_rate.to_bits(),
+        )?;
+        self.set_page(BNO055RegisterPage::Page0)?;
+
+        self.acc_range = config.acc_range;
+        self.gyr_range = config.gyr_range;
+        Ok(())
+    }
+
+    /// Enables the given interrupt sources and routes them to the INT pin by
+    /// writing INT_EN and INT_MSK, see
+    /// [BNO055InterruptSources](struct.BNO055InterruptSources.html). These
+    /// registers live on page 1.
+    pub fn set_interrupt_sources(&mut self, sources: BNO055InterruptSources) -> Result<(), Error<I::Error>> {
+        self.set_page(BNO055RegisterPage::Page1)?;
+        self.interface.write_register(
+            BNO055_INT_EN,
+            sources.to_bits(),
+        )?;
+        self.interface.write_register(
+            BNO055_INT_MSK,
+            sources.to_bits(),
+        )?;
+        self.set_page(BNO055RegisterPage::Page0)?;
+        Ok(())
+    }
+
+    /// Sets the accelerometer any-motion/no-motion/slow-motion/high-g
+    /// thresholds and durations, see
+    /// [BNO055AccMotionConfig](struct.BNO055AccMotionConfig.html). These
+    /// registers live on page 1.
+    pub fn set_acc_motion_config(&mut self, config: BNO055AccMotionConfig) -> Result<(), Error<I::Error>> {
+        self.set_page(BNO055RegisterPage::Page1)?;
+        self.interface.write_register(
+            BNO055_ACC_AM_THRES,
+            config.any_motion_threshold,
+        )?;
+        self.interface.write_register(
+            BNO055_ACC_INT_SETTINGS,
+            config.int_settings_bits(),
+        )?;
+        self.interface.write_register(
+            BNO055_ACC_HG_DURATION,
+            config.high_g_duration,
+        )?;
+        self.interface.write_register(
+            BNO055_ACC_HG_THRES,
+            config.high_g_threshold,
+        )?;
+        self.interface.write_register(
+            BNO055_ACC_NM_THRES,
+            config.no_motion_threshold,
         )?;
+        self.interface.write_register(
+            BNO055_ACC_NM_SET,
+            config.nm_set_bits(),
+        )?;
+        self.set_page(BNO055RegisterPage::Page0)?;
+        Ok(())
+    }
+
+    /// Decodes INT_STA (0x37) into per-source interrupt flags, see
+    /// [BNO055InterruptStatus](struct.BNO055InterruptStatus.html).
+    pub fn get_interrupt_status(&mut self) -> Result<BNO055InterruptStatus, Error<I::Error>> {
+        let status = self.interface.read_register(BNO055_INT_STA)?;
+        Ok(BNO055InterruptStatus {
+            acc_bsx: status & 0b0000_0001 != 0,
+            gyro_am: status & 0b0000_0100 != 0,
+            gyro_high_rate: status & 0b0000_1000 != 0,
+            acc_high_g: status & 0b0010_0000 != 0,
+            acc_am: status & 0b0100_0000 != 0,
+            acc_nm: status & 0b1000_0000 != 0,
+        })
+    }
+
+    /// Clears the latched interrupt status bits by pulsing the RST_INT bit of
+    /// SYS_TRIGGER (0x3F).
+    pub fn clear_interrupts(&mut self) -> Result<(), Error<I::Error>> {
+        let sys_trigger = self.interface.read_register(BNO055_SYS_TRIGGER)?;
+        self.interface.write_register(
+            BNO055_SYS_TRIGGER,
+            sys_trigger | 0b0100_0000,
+        )?;
+        Ok(())
+    }
+
+    /// Get euler angle representation of orientation.
+    /// The `x` component is the heading, `y` is the roll, `z` is pitch, in the
+    /// unit selected via [set_units](fn.set_units.html) (degrees by default)
+    pub fn get_euler(&mut self) -> Result<Vec3, Error<I::Error>> {
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_EUL_HEADING_LSB, &mut buf)?;
         let x = LittleEndian::read_i16(&buf[0..2]) as f32;
         let y = LittleEndian::read_i16(&buf[2..4]) as f32;
         let z = LittleEndian::read_i16(&buf[4..6]) as f32;
 
-        let scale = 1.0 / 900.0;
+        let scale = self.units.eul_scale();
         Ok(Vec3 {
             x: x * scale,
             y: y * scale,
@@ -421,16 +1396,14 @@ where
         })
     }
 
-    pub fn get_linear_acceleration(&mut self) -> Result<Vec3, T::Error> {
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_LIA_DATA_X_LSB,
-            6,
-        )?;
+    pub fn get_linear_acceleration(&mut self) -> Result<Vec3, Error<I::Error>> {
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_LIA_DATA_X_LSB, &mut buf)?;
         let x = LittleEndian::read_i16(&buf[0..2]) as f32;
         let y = LittleEndian::read_i16(&buf[2..4]) as f32;
         let z = LittleEndian::read_i16(&buf[4..6]) as f32;
 
-        let scale = 1.0 / 100.0;
+        let scale = self.units.acc_scale();
         Ok(Vec3 {
             x: x * scale,
             y: y * scale,
@@ -438,20 +1411,34 @@ where
         })
     }
 
-    // TODO: linear acceleration, gravity
+    /// Gets the gravity vector, the fusion engine's estimate of the
+    /// acceleration due to gravity alone, separated out from the
+    /// accelerometer's total measured acceleration
+    pub fn get_gravity(&mut self) -> Result<Vec3, Error<I::Error>> {
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_GRV_DATA_X_LSB, &mut buf)?;
+        let x = LittleEndian::read_i16(&buf[0..2]) as f32;
+        let y = LittleEndian::read_i16(&buf[2..4]) as f32;
+        let z = LittleEndian::read_i16(&buf[4..6]) as f32;
+
+        let scale = self.units.acc_scale();
+        Ok(Vec3 {
+            x: x * scale,
+            y: y * scale,
+            z: z * scale,
+        })
+    }
 }
 
-impl<T> Magnetometer for BNO055<T>
+impl<I> Magnetometer for BNO055<I>
 where
-    T: I2CDevice + Sized,
+    I: Bno055Interface,
 {
-    type Error = T::Error;
+    type Error = Error<I::Error>;
 
     fn magnetic_reading(&mut self) -> Result<Vec3, Self::Error> {
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_MAG_DATA_X_LSB,
-            6,
-        )?;
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_MAG_DATA_X_LSB, &mut buf)?;
         let x = LittleEndian::read_i16(&buf[0..2]) as f32;
         let y = LittleEndian::read_i16(&buf[2..4]) as f32;
         let z = LittleEndian::read_i16(&buf[4..6]) as f32;
@@ -465,22 +1452,20 @@ where
     }
 }
 
-impl<T> Gyroscope for BNO055<T>
+impl<I> Gyroscope for BNO055<I>
 where
-    T: I2CDevice + Sized,
+    I: Bno055Interface,
 {
-    type Error = T::Error;
+    type Error = Error<I::Error>;
 
     fn angular_rate_reading(&mut self) -> Result<Vec3, Self::Error> {
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_GYR_DATA_X_LSB,
-            6,
-        )?;
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_GYR_DATA_X_LSB, &mut buf)?;
         let x = LittleEndian::read_i16(&buf[0..2]) as f32;
         let y = LittleEndian::read_i16(&buf[2..4]) as f32;
         let z = LittleEndian::read_i16(&buf[4..6]) as f32;
 
-        let scale = 1.0 / 900.0;
+        let scale = self.effective_gyr_scale();
         Ok(Vec3 {
             x: x * scale,
             y: y * scale,
@@ -489,22 +1474,20 @@ where
     }
 }
 
-impl<T> Accelerometer for BNO055<T>
+impl<I> Accelerometer for BNO055<I>
 where
-    T: I2CDevice + Sized,
+    I: Bno055Interface,
 {
-    type Error = T::Error;
+    type Error = Error<I::Error>;
 
     fn acceleration_reading(&mut self) -> Result<Vec3, Self::Error> {
-        let buf = self.i2cdev.smbus_read_i2c_block_data(
-            BNO055_ACC_DATA_X_LSB,
-            6,
-        )?;
+        let mut buf = [0u8; 6];
+        self.interface.read_registers(BNO055_ACC_DATA_X_LSB, &mut buf)?;
         let x = LittleEndian::read_i16(&buf[0..2]) as f32;
         let y = LittleEndian::read_i16(&buf[2..4]) as f32;
         let z = LittleEndian::read_i16(&buf[4..6]) as f32;
 
-        let scale = 1.0 / 100.0;
+        let scale = self.effective_acc_scale();
         Ok(Vec3 {
             x: x * scale,
             y: y * scale,
@@ -513,19 +1496,157 @@ where
     }
 }
 
-impl<T> Thermometer for BNO055<T>
+impl<I> Thermometer for BNO055<I>
 where
-    T: I2CDevice + Sized,
+    I: Bno055Interface,
 {
-    type Error = T::Error;
+    type Error = Error<I::Error>;
 
     fn temperature_celsius(&mut self) -> Result<f32, Self::Error> {
-        Ok(self.i2cdev.smbus_read_byte_data(BNO055_TEMP)? as u8 as f32)
+        let raw = self.interface.read_register(BNO055_TEMP)? as i8 as f32;
+        Ok(match self.units.temp {
+            BNO055TemperatureUnit::Celsius => raw,
+            BNO055TemperatureUnit::Fahrenheit => (raw / 2.0 - 32.0) * 5.0 / 9.0,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {}
+
+    #[test]
+    fn calibration_round_trips_through_bytes() {
+        let calibration = BNO055Calibration {
+            acc_offset_x: 1,
+            acc_offset_y: -2,
+            acc_offset_z: 3,
+            mag_offset_x: -4,
+            mag_offset_y: 5,
+            mag_offset_z: -6,
+            gyr_offset_x: 7,
+            gyr_offset_y: -8,
+            gyr_offset_z: 9,
+            acc_radius: -10,
+            mag_radius: 11,
+        };
+        let bytes = calibration.to_bytes();
+        assert_eq!(BNO055Calibration::from_bytes(&bytes).unwrap(), calibration);
+    }
+
+    #[test]
+    fn calibration_from_bytes_rejects_wrong_length() {
+        let bytes = [0u8; BNO055_CALIBRATION_SIZE - 1];
+        assert_eq!(
+            BNO055Calibration::from_bytes(&bytes),
+            Err(BNO055CalibrationError::InvalidLength(bytes.len()))
+        );
+    }
+
+    #[test]
+    fn units_to_byte_packs_non_default_selections() {
+        let units = BNO055Units {
+            acc: BNO055AccUnit::Mg,
+            gyr: BNO055AngularRateUnit::Rps,
+            eul: BNO055EulerUnit::Radians,
+            temp: BNO055TemperatureUnit::Fahrenheit,
+            orientation: BNO055OrientationMode::Android,
+        };
+        assert_eq!(units.to_byte(), 0b1001_0111);
+    }
+
+    #[test]
+    fn units_to_byte_defaults_to_zero() {
+        assert_eq!(BNO055Units::default().to_byte(), 0);
+    }
+
+    #[test]
+    fn axis_remap_presets_use_distinct_source_axes() {
+        use BNO055AxisRemapPreset::*;
+        for preset in &[P0, P1, P2, P3, P4, P5, P6, P7] {
+            let remap = preset.remap();
+            assert_ne!(remap.x_source, remap.y_source);
+            assert_ne!(remap.y_source, remap.z_source);
+            assert_ne!(remap.x_source, remap.z_source);
+        }
+    }
+
+    #[test]
+    fn axis_remap_preset_p1_is_identity() {
+        let remap = BNO055AxisRemapPreset::P1.remap();
+        assert_eq!(remap.x_source, BNO055Axis::X);
+        assert_eq!(remap.y_source, BNO055Axis::Y);
+        assert_eq!(remap.z_source, BNO055Axis::Z);
+        assert!(!remap.x_negate && !remap.y_negate && !remap.z_negate);
+    }
+
+    #[test]
+    fn axis_remap_new_rejects_colliding_source_axes() {
+        use BNO055Axis::*;
+        assert_eq!(BNO055AxisRemap::new(X, X, Z, false, false, false), None);
+        assert_eq!(BNO055AxisRemap::new(X, Y, Y, false, false, false), None);
+        assert_eq!(BNO055AxisRemap::new(Z, Y, Z, false, false, false), None);
+        assert!(BNO055AxisRemap::new(X, Y, Z, false, false, false).is_some());
+    }
+
+    #[test]
+    fn amg_config_packs_acc_config_bits() {
+        let config = BNO055AmgConfig {
+            acc_range: BNO055AccRange::G8,
+            acc_bandwidth: BNO055AccBandwidth::Hz250,
+            ..Default::default()
+        };
+        assert_eq!(config.acc_config_bits(), 0b1_0110);
+    }
+
+    #[test]
+    fn amg_config_packs_gyr_config_0_bits() {
+        let config = BNO055AmgConfig {
+            gyr_range: BNO055GyrRange::Dps250,
+            gyr_bandwidth: BNO055GyrBandwidth::Hz116,
+            ..Default::default()
+        };
+        assert_eq!(config.gyr_config_0_bits(), 0b011_010);
+    }
+
+    #[test]
+    fn interrupt_sources_to_bits_packs_all_sources() {
+        let sources = BNO055InterruptSources {
+            acc_any_motion: true,
+            acc_high_g: true,
+            acc_no_motion: true,
+            gyro_any_motion: true,
+            gyro_high_rate: true,
+        };
+        assert_eq!(sources.to_bits(), 0b1110_1100);
+    }
+
+    #[test]
+    fn interrupt_sources_to_bits_defaults_to_zero() {
+        assert_eq!(BNO055InterruptSources::default().to_bits(), 0);
+    }
+
+    #[test]
+    fn acc_motion_config_packs_int_settings_bits() {
+        let config = BNO055AccMotionConfig {
+            any_motion_duration: 0b10,
+            any_motion_x: true,
+            high_g_z: true,
+            ..Default::default()
+        };
+        assert_eq!(config.int_settings_bits(), 0b1000_0110);
+    }
+
+    #[test]
+    fn acc_motion_config_packs_nm_set_bits() {
+        let config = BNO055AccMotionConfig {
+            no_motion_duration: 0b0010_1010,
+            slow_motion: true,
+            ..Default::default()
+        };
+        assert_eq!(config.nm_set_bits(), 0b0101_0101);
+    }
 }